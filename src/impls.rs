@@ -124,11 +124,14 @@ where
     fn type_info() -> Type {
         Type::builder()
             .path(Path::prelude("Option"))
-            .type_params(tuple_meta_type![T])
+            .type_params(vec![MetaType::parameter::<Self, T>("T")])
             .variant(
                 Variants::with_fields()
                     .variant_unit("None")
-                    .variant("Some", Fields::unnamed().field_of::<T>("T")),
+                    .variant(
+                        "Some",
+                        Fields::unnamed().field(MetaType::parameter::<Self, T>("T"), "T"),
+                    ),
             )
     }
 }
@@ -143,11 +146,20 @@ where
     fn type_info() -> Type {
         Type::builder()
             .path(Path::prelude("Result"))
-            .type_params(tuple_meta_type!(T, E))
+            .type_params(vec![
+                MetaType::parameter::<Self, T>("T"),
+                MetaType::parameter::<Self, E>("E"),
+            ])
             .variant(
                 Variants::with_fields()
-                    .variant("Ok", Fields::unnamed().field_of::<T>("T"))
-                    .variant("Err", Fields::unnamed().field_of::<E>("E")),
+                    .variant(
+                        "Ok",
+                        Fields::unnamed().field(MetaType::parameter::<Self, T>("T"), "T"),
+                    )
+                    .variant(
+                        "Err",
+                        Fields::unnamed().field(MetaType::parameter::<Self, E>("E"), "E"),
+                    ),
             )
     }
 }
@@ -160,6 +172,10 @@ where
     type Identity = Self;
 
     fn type_info() -> Type {
+        // The single field holds both `K` and `V` together as a slice of
+        // entries, so it can't be expressed as a reference to just one of
+        // `BTreeMap`'s own parameters the way `Option`'s or `Result`'s
+        // fields can.
         Type::builder()
             .path(Path::prelude("BTreeMap"))
             .type_params(tuple_meta_type![(K, V)])
@@ -176,8 +192,8 @@ where
     fn type_info() -> Type {
         Type::builder()
             .path(Path::prelude("Box"))
-            .type_params(tuple_meta_type![T])
-            .composite(Fields::unnamed().field_of::<T>("T"))
+            .type_params(vec![MetaType::parameter::<Self, T>("T")])
+            .composite(Fields::unnamed().field(MetaType::parameter::<Self, T>("T"), "T"))
     }
 }
 
@@ -210,6 +226,11 @@ where
     type Identity = Self;
 
     fn type_info() -> Type {
+        // Unlike Option/Result/Box, this doesn't go through
+        // `Type::builder().type_params(..)`, so there's nowhere for a "T"
+        // parameter to be declared for `MetaType::parameter` to resolve
+        // against. Keep the element concrete here, the same as the other
+        // bare `TypeDefXxx::new(..).into()` impls (Array, Phantom, Compact).
         TypeDefSequence::of::<T>().into()
     }
 }
@@ -36,6 +36,13 @@
 //! `scale-info` provides implementations for all commonly used Rust standard
 //! types and a derive macro for implementing of custom types.
 //!
+//! Of those standard impls, `Option`, `Result` and `Box` declare their own
+//! fields in terms of [`MetaType::parameter`](`crate::MetaType::parameter`),
+//! so the registry can tell that e.g. `Result::Ok`'s field *is* `Result`'s
+//! own `T`. `Vec`/`[T]` and `BTreeMap`'s field can't be expressed that way
+//! (see the comments on those impls in `impls.rs`) and still report a bare
+//! concrete type.
+//!
 //! # Forms
 //!
 //! To bridge between compile-time type information and runtime the
@@ -116,7 +123,13 @@ mod utils;
 mod tests;
 
 pub use self::{
-    meta_type::MetaType,
+    meta_type::{
+        MetaType,
+        MetaTypeConcrete,
+        MetaTypeGeneric,
+        MetaTypeParameter,
+        MetaTypeParameterized,
+    },
     registry::{
         IntoPortable,
         PortableRegistry,
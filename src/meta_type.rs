@@ -0,0 +1,303 @@
+// Copyright 2019-2021 Parity Technologies (UK) Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::prelude::vec::Vec;
+
+use core::any::TypeId;
+
+use crate::{
+    Type,
+    TypeInfo,
+};
+
+/// A type which refers to a type definition, as used in fields, variants and
+/// type parameters throughout the registry.
+///
+/// Besides a fully substituted [`Concrete`](`MetaType::Concrete`) type, a
+/// `MetaType` can also stand for a symbolic [`Parameter`](`MetaType::Parameter`)
+/// of some owning type, a [`Parameterized`](`MetaType::Parameterized`) generic
+/// type paired with the concrete types filling in its own parameters, or the
+/// bare [`Generic`](`MetaType::Generic`) shell of a generic type with none of
+/// its parameters substituted.
+///
+/// # Note
+///
+/// Capturing only the fully substituted [`Concrete`](`MetaType::Concrete`) type, as this type used
+/// to do, loses the relationship between e.g. a `Vec<T>`'s element field and
+/// the `T` of the enclosing type: the registry only ever sees the concrete
+/// type that ended up filling `T`. The other variants let `TypeInfo` impls
+/// declare a field as "this is the owning type's parameter `T`" instead,
+/// so that the relationship survives into the registry.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum MetaType {
+    /// A fully substituted, concrete type.
+    Concrete(MetaTypeConcrete),
+    /// A symbolic reference to one of the owning type's own generic
+    /// parameters.
+    Parameter(MetaTypeParameter),
+    /// A concrete generic type, paired with the concrete types substituted
+    /// for each of its own parameters.
+    Parameterized(MetaTypeParameterized),
+    /// The bare shell of a generic type, without any of its parameters
+    /// substituted.
+    Generic(MetaTypeGeneric),
+}
+
+impl MetaType {
+    /// Creates a new `MetaType` from a concrete type, fully substituted.
+    ///
+    /// This is the behaviour `MetaType` has always had: it captures `T`'s
+    /// [`TypeId`] and its [`TypeInfo::type_info`].
+    pub fn new<T>() -> Self
+    where
+        T: TypeInfo + ?Sized + 'static,
+    {
+        MetaType::Concrete(MetaTypeConcrete::new::<T>())
+    }
+
+    /// Creates a `MetaType` standing for the generic parameter `name` of
+    /// `Owner`, itself substituted with the concrete type `P`.
+    ///
+    /// # Example
+    ///
+    /// `MetaType::parameter::<Option<u8>, u8>("T")` records that the `T`
+    /// referenced by `Option`'s own field resolves to `u8` whenever
+    /// `Option<u8>` is the owning, enclosing type. `Option`, `Result` and
+    /// `Box` all declare their fields this way.
+    pub fn parameter<Owner, P>(name: &'static str) -> Self
+    where
+        Owner: 'static,
+        P: TypeInfo + ?Sized + 'static,
+    {
+        MetaType::Parameter(MetaTypeParameter::new::<Owner, P>(name))
+    }
+
+    /// Creates a `MetaType` for the concrete generic type `T`, paired with
+    /// the concrete types substituted for each of its own parameters.
+    pub fn parameterized<T>(params: &[MetaType]) -> Self
+    where
+        T: TypeInfo + ?Sized + 'static,
+    {
+        MetaType::Parameterized(MetaTypeParameterized::new::<T>(params))
+    }
+
+    /// Creates a `MetaType` for the bare generic type `T`, without any of
+    /// its parameters substituted.
+    pub fn generic<T>() -> Self
+    where
+        T: 'static,
+    {
+        MetaType::Generic(MetaTypeGeneric::new::<T>())
+    }
+
+    /// Returns the [`TypeId`] of the underlying type.
+    ///
+    /// For a [`Parameter`](`MetaType::Parameter`) this is the id of the
+    /// *substituted* concrete type, not the symbolic parameter itself.
+    pub fn type_id(&self) -> TypeId {
+        match self {
+            MetaType::Concrete(ty) => ty.type_id,
+            MetaType::Parameter(param) => param.concrete_type_id,
+            MetaType::Parameterized(ty) => ty.type_id,
+            MetaType::Generic(ty) => ty.type_id,
+        }
+    }
+
+    /// Returns the static type information of the underlying type, if any.
+    ///
+    /// Every variant other than [`Generic`](`MetaType::Generic`) resolves to
+    /// a concrete type and so has one; a bare [`Generic`](`MetaType::Generic`) shell has no
+    /// parameters substituted and so nothing to resolve to, hence `None`.
+    /// This is the single place callers outside this module (e.g. the
+    /// registry) should go through to turn an arbitrary `MetaType` into a
+    /// `Type`, rather than matching on the enum themselves.
+    pub fn type_info(&self) -> Option<Type> {
+        match self {
+            MetaType::Concrete(ty) => Some(ty.type_info()),
+            MetaType::Parameter(param) => Some(param.type_info()),
+            MetaType::Parameterized(ty) => Some(ty.type_info()),
+            MetaType::Generic(_) => None,
+        }
+    }
+}
+
+/// A fully substituted, concrete type, as registered by its [`TypeInfo`]
+/// implementation.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct MetaTypeConcrete {
+    type_id: TypeId,
+    type_info: fn() -> Type,
+}
+
+impl MetaTypeConcrete {
+    fn new<T>() -> Self
+    where
+        T: TypeInfo + ?Sized + 'static,
+    {
+        Self {
+            type_id: TypeId::of::<T>(),
+            type_info: <T as TypeInfo>::type_info,
+        }
+    }
+
+    /// Returns the static type information of the concrete type.
+    pub fn type_info(&self) -> Type {
+        (self.type_info)()
+    }
+}
+
+/// A symbolic reference to one of the owning type's own generic parameters,
+/// e.g. the `T` in `Option<T>`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct MetaTypeParameter {
+    owner_type_id: TypeId,
+    concrete_type_id: TypeId,
+    concrete_type_info: fn() -> Type,
+    name: &'static str,
+}
+
+impl MetaTypeParameter {
+    fn new<Owner, P>(name: &'static str) -> Self
+    where
+        Owner: 'static,
+        P: TypeInfo + ?Sized + 'static,
+    {
+        Self {
+            owner_type_id: TypeId::of::<Owner>(),
+            concrete_type_id: TypeId::of::<P>(),
+            concrete_type_info: <P as TypeInfo>::type_info,
+            name,
+        }
+    }
+
+    /// The name of the parameter, e.g. `"T"`.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The [`TypeId`] of the concrete type that owns this parameter.
+    pub fn owner_type_id(&self) -> TypeId {
+        self.owner_type_id
+    }
+
+    /// Returns the static type information of the concrete type substituted
+    /// for this parameter.
+    pub fn type_info(&self) -> Type {
+        (self.concrete_type_info)()
+    }
+}
+
+/// A concrete generic type, paired with the concrete [`MetaType`]s
+/// substituted for each of its own parameters.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct MetaTypeParameterized {
+    type_id: TypeId,
+    type_info: fn() -> Type,
+    params: Vec<MetaType>,
+}
+
+impl MetaTypeParameterized {
+    fn new<T>(params: &[MetaType]) -> Self
+    where
+        T: TypeInfo + ?Sized + 'static,
+    {
+        Self {
+            type_id: TypeId::of::<T>(),
+            type_info: <T as TypeInfo>::type_info,
+            params: params.to_vec(),
+        }
+    }
+
+    /// Returns the static type information of the generic type.
+    pub fn type_info(&self) -> Type {
+        (self.type_info)()
+    }
+
+    /// Returns the concrete [`MetaType`]s substituted for this type's own
+    /// parameters, in declaration order.
+    pub fn params(&self) -> &[MetaType] {
+        &self.params
+    }
+}
+
+/// The bare shell of a generic type, with none of its parameters
+/// substituted, e.g. `Vec` on its own.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct MetaTypeGeneric {
+    type_id: TypeId,
+}
+
+impl MetaTypeGeneric {
+    fn new<T>() -> Self
+    where
+        T: 'static,
+    {
+        Self {
+            type_id: TypeId::of::<T>(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Owner;
+
+    #[test]
+    fn concrete_resolves_to_its_own_type() {
+        let meta = MetaType::new::<u8>();
+
+        assert_eq!(meta.type_id(), TypeId::of::<u8>());
+        assert_eq!(meta.type_info(), Some(u8::type_info()));
+    }
+
+    #[test]
+    fn parameter_resolves_to_the_substituted_type_not_the_owner() {
+        let meta = MetaType::parameter::<Owner, u8>("T");
+
+        assert_eq!(meta.type_id(), TypeId::of::<u8>());
+        assert_eq!(meta.type_info(), Some(u8::type_info()));
+
+        match meta {
+            MetaType::Parameter(param) => {
+                assert_eq!(param.name(), "T");
+                assert_eq!(param.owner_type_id(), TypeId::of::<Owner>());
+            }
+            _ => panic!("expected MetaType::Parameter"),
+        }
+    }
+
+    #[test]
+    fn parameterized_resolves_to_the_generic_type_itself() {
+        let params = [MetaType::new::<u8>()];
+        let meta = MetaType::parameterized::<Option<u8>>(&params);
+
+        assert_eq!(meta.type_id(), TypeId::of::<Option<u8>>());
+        assert_eq!(meta.type_info(), Some(Option::<u8>::type_info()));
+
+        match meta {
+            MetaType::Parameterized(ty) => assert_eq!(ty.params(), &params),
+            _ => panic!("expected MetaType::Parameterized"),
+        }
+    }
+
+    #[test]
+    fn generic_has_no_type_info() {
+        let meta = MetaType::generic::<Option<u8>>();
+
+        assert_eq!(meta.type_id(), TypeId::of::<Option<u8>>());
+        assert_eq!(meta.type_info(), None);
+    }
+}